@@ -9,7 +9,9 @@ use vigem::*;
 use serde::{Deserialize, Serialize};
 
 use std::collections::{HashMap, VecDeque};
+use std::fs;
 use std::hint::spin_loop;
+use std::path::PathBuf;
 use std::sync::mpsc;
 use std::time::{Duration, Instant};
 
@@ -42,6 +44,53 @@ pub enum ControllerAction {
     Analog(f64, f64),
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub enum SampleKernel {
+    Linear,
+    Exponential,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum ResponseCurve {
+    None,
+    Power { gamma: f64 },
+    PiecewiseLinear { points: Vec<(f64, f64)> },
+}
+
+impl ResponseCurve {
+    // `interpolate_piecewise` runs on every loop iteration of a busy-spinning
+    // real-time input loop, so breakpoints are sorted/deduped once here, at
+    // config load time, rather than re-validated on every call.
+    fn normalized(self) -> Self {
+        match self {
+            ResponseCurve::PiecewiseLinear { mut points } => {
+                points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+                points.dedup_by(|a, b| a.0 == b.0);
+
+                ResponseCurve::PiecewiseLinear { points }
+            }
+            other => other,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub enum BindMode {
+    Hold,
+    Toggle,
+    TapHold {
+        threshold: Duration,
+        tap_action: ControllerAction,
+    },
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct BindState {
+    time_pressed: Option<Instant>,
+    hold_started: bool,
+    toggle: bool,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(default, deny_unknown_fields)]
 pub struct Config {
@@ -56,11 +105,25 @@ pub struct Config {
     oversteer_alert_threshold: f64,
     oversteer_alert: tone_generator::Config,
 
+    rumble_alert_enabled: bool,
+    rumble_alert_threshold: f64,
+
     analog_mask: (bool, bool),
     analog_circularize: bool,
     mouse_button_fix: bool,
 
+    analog_inertia: bool,
+    analog_inertia_friction: f64,
+
+    sample_kernel: SampleKernel,
+    sample_tau: Duration,
+
+    response_curve: ResponseCurve,
+
+    tap_pulse_duration: Duration,
+
     binds: HashMap<Bind, ControllerAction>,
+    bind_modes: HashMap<Bind, BindMode>,
     dodge_binds: HashMap<DodgeAction, Bind>,
 }
 
@@ -78,11 +141,25 @@ impl Default for Config {
             oversteer_alert_threshold: 1.5,
             oversteer_alert: tone_generator::Config::default(),
 
+            rumble_alert_enabled: false,
+            rumble_alert_threshold: 0.5,
+
             analog_mask: (true, true),
             analog_circularize: true,
             mouse_button_fix: false,
 
+            analog_inertia: false,
+            analog_inertia_friction: 0.0001,
+
+            sample_kernel: SampleKernel::Linear,
+            sample_tau: Duration::from_millis(10),
+
+            response_curve: ResponseCurve::None,
+
+            tap_pulse_duration: Duration::from_millis(50),
+
             binds: HashMap::new(),
+            bind_modes: HashMap::new(),
             dodge_binds: HashMap::new(),
         }
     }
@@ -90,9 +167,12 @@ impl Default for Config {
 
 pub struct EventHandler {
     config: Config,
+    config_path: PathBuf,
 
     rx: mpsc::Receiver<Event>,
 
+    pending_bind_capture: Option<ControllerAction>,
+
     vigem: Vigem,
     target: Target,
     report: XUSBReport,
@@ -102,6 +182,14 @@ pub struct EventHandler {
     mouse_samples: VecDeque<(i32, i32, Instant)>,
     mouse_button_states: (KeyState, KeyState),
 
+    analog_binds: HashMap<Bind, (f64, f64)>,
+
+    bind_states: HashMap<Bind, BindState>,
+    pending_tap_releases: Vec<(Bind, ControllerButton, Instant)>,
+
+    analog_velocity: (f64, f64),
+    analog_velocity_last_update: Instant,
+
     analog_locked: bool,
     analog_lock_end: Instant,
 
@@ -116,7 +204,14 @@ pub struct EventHandler {
 impl EventHandler {
     const ANALOG_MAX: f64 = -(i16::MIN as f64);
 
-    pub fn new(rx: mpsc::Receiver<Event>, config: Config) -> Result<Self, anyhow::Error> {
+    pub fn new(
+        tx: mpsc::Sender<Event>,
+        rx: mpsc::Receiver<Event>,
+        mut config: Config,
+        config_path: PathBuf,
+    ) -> Result<Self, anyhow::Error> {
+        config.response_curve = config.response_curve.normalized();
+
         let mut vigem = Vigem::new();
         vigem.connect()?;
 
@@ -126,20 +221,31 @@ impl EventHandler {
         info!("ViGEm connected, controller index: {}", target.index());
 
         info!(
-            "sensitivity: {}, sample_window: {:#?}, dodge_lock_duration: {:#?}",
-            config.sensitivity, config.sample_window, config.dodge_lock_duration
+            "sensitivity: {}, sample_window: {:#?}, sample_kernel: {:?}, sample_tau: {:#?}, dodge_lock_duration: {:#?}",
+            config.sensitivity,
+            config.sample_window,
+            config.sample_kernel,
+            config.sample_tau,
+            config.dodge_lock_duration
         );
 
-        let tone_generator = match config.oversteer_alert_enabled {
+        target.register_notification(move |large_motor, small_motor, _led_number| {
+            let _ = tx.send(Event::Rumble(large_motor, small_motor));
+        })?;
+
+        let tone_generator = match Self::tone_generator_needed(&config) {
             true => Some(ToneGenerator::new(config.oversteer_alert)?),
             false => None,
         };
 
         Ok(EventHandler {
             config: config,
+            config_path: config_path,
 
             rx: rx,
 
+            pending_bind_capture: None,
+
             vigem: vigem,
             target: target,
             report: XUSBReport::default(),
@@ -149,6 +255,14 @@ impl EventHandler {
             mouse_samples: VecDeque::new(),
             mouse_button_states: (KeyState::Up, KeyState::Up),
 
+            analog_binds: HashMap::new(),
+
+            bind_states: HashMap::new(),
+            pending_tap_releases: Vec::new(),
+
+            analog_velocity: (0.0, 0.0),
+            analog_velocity_last_update: Instant::now(),
+
             analog_locked: false,
             analog_lock_end: Instant::now(),
 
@@ -176,76 +290,108 @@ impl EventHandler {
             }
 
             if let Ok(event) = event {
-                match event {
-                    Event::MouseMove(x, y) => self.handle_mouse_move(x, y),
-
-                    Event::MouseButton(button, state) => {
-                        if button == MouseButton::Left {
-                            self.mouse_button_states.0 = state;
+                if let Some(action) = self.pending_bind_capture.take() {
+                    match event {
+                        Event::Keyboard(scancode, KeyState::Down) => {
+                            self.capture_bind(Bind::Keyboard(scancode), action);
                         }
-
-                        if button == MouseButton::Right {
-                            self.mouse_button_states.1 = state;
+                        Event::MouseButton(button, KeyState::Down) => {
+                            self.capture_bind(Bind::Mouse(button), action);
+                        }
+                        _ => {
+                            self.pending_bind_capture = Some(action);
                         }
+                    }
+                } else {
+                    match event {
+                        Event::MouseMove(x, y) => self.handle_mouse_move(x, y),
 
-                        self.handle_bind(Bind::Mouse(button), state);
+                        Event::MouseButton(button, state) => {
+                            if button == MouseButton::Left {
+                                self.mouse_button_states.0 = state;
+                            }
 
-                        if self.config.mouse_button_fix && state == KeyState::Up {
-                            if self.mouse_button_states.0 == KeyState::Down {
-                                self.handle_bind(Bind::Mouse(MouseButton::Left), KeyState::Down)
+                            if button == MouseButton::Right {
+                                self.mouse_button_states.1 = state;
                             }
 
-                            if self.mouse_button_states.1 == KeyState::Down {
-                                self.handle_bind(Bind::Mouse(MouseButton::Right), KeyState::Down)
+                            self.handle_bind(Bind::Mouse(button), state);
+
+                            if self.config.mouse_button_fix && state == KeyState::Up {
+                                if self.mouse_button_states.0 == KeyState::Down {
+                                    self.handle_bind(Bind::Mouse(MouseButton::Left), KeyState::Down)
+                                }
+
+                                if self.mouse_button_states.1 == KeyState::Down {
+                                    self.handle_bind(
+                                        Bind::Mouse(MouseButton::Right),
+                                        KeyState::Down,
+                                    )
+                                }
                             }
                         }
-                    }
 
-                    Event::Keyboard(scancode, state) => {
-                        self.handle_bind(Bind::Keyboard(scancode), state);
-                        if state == KeyState::Up && scancode == ic::ScanCode::W {
-                            w = false;
-                        }
-                        if state == KeyState::Up && scancode == ic::ScanCode::A {
-                            a = false;
-                        }
-                        if state == KeyState::Up && scancode == ic::ScanCode::S {
-                            s = false;
-                        }
-                        if state == KeyState::Up && scancode == ic::ScanCode::D {
-                            d = false;
-                        }
-                        if state == KeyState::Down && scancode == ic::ScanCode::W {
-                            w = true;
-                        }
-                        if state == KeyState::Down && scancode == ic::ScanCode::A {
-                            a = true;
-                        }
-                        if state == KeyState::Down && scancode == ic::ScanCode::S {
-                            s = true;
+                        Event::Keyboard(scancode, state) => {
+                            self.handle_bind(Bind::Keyboard(scancode), state);
+                            if state == KeyState::Up && scancode == ic::ScanCode::W {
+                                w = false;
+                            }
+                            if state == KeyState::Up && scancode == ic::ScanCode::A {
+                                a = false;
+                            }
+                            if state == KeyState::Up && scancode == ic::ScanCode::S {
+                                s = false;
+                            }
+                            if state == KeyState::Up && scancode == ic::ScanCode::D {
+                                d = false;
+                            }
+                            if state == KeyState::Down && scancode == ic::ScanCode::W {
+                                w = true;
+                            }
+                            if state == KeyState::Down && scancode == ic::ScanCode::A {
+                                a = true;
+                            }
+                            if state == KeyState::Down && scancode == ic::ScanCode::S {
+                                s = true;
+                            }
+                            if state == KeyState::Down && scancode == ic::ScanCode::D {
+                                d = true;
+                            }
+                            if w == s {
+                                self.report.s_thumb_ly = 0;
+                            } else {
+                                self.report.s_thumb_ly = if w { i16::MAX } else { i16::MIN };
+                            }
+                            if a == d {
+                                self.report.s_thumb_lx = 0;
+                            } else {
+                                self.report.s_thumb_lx = if d { i16::MAX } else { i16::MIN };
+                            }
                         }
-                        if state == KeyState::Down && scancode == ic::ScanCode::D {
-                            d = true;
+
+                        Event::Reset => {
+                            self.mouse_button_states = (KeyState::Up, KeyState::Up);
+                            self.report = XUSBReport::default();
                         }
-                        if w == s {
-                            self.report.s_thumb_ly = 0;
-                        } else {
-                            self.report.s_thumb_ly = if w { i16::MAX } else { i16::MIN };
+
+                        Event::Rumble(large_motor, small_motor) => {
+                            self.handle_rumble(large_motor, small_motor)
                         }
-                        if a == d {
-                            self.report.s_thumb_lx = 0;
-                        } else {
-                            self.report.s_thumb_lx = if d { i16::MAX } else { i16::MIN };
+
+                        Event::EnterBindMode(action) => {
+                            self.pending_bind_capture = Some(action);
                         }
-                    }
 
-                    Event::Reset => {
-                        self.mouse_button_states = (KeyState::Up, KeyState::Up);
-                        self.report = XUSBReport::default();
+                        Event::ReloadConfig => {
+                            if let Err(err) = self.reload_config() {
+                                error!("failed to reload config: {:#}", err);
+                            }
+                        }
                     }
                 }
             }
 
+            self.update_bind_modifiers();
             self.update_analog();
             self.vigem.update(&self.target, &self.report)?;
 
@@ -270,15 +416,92 @@ impl EventHandler {
     }
 
     fn handle_bind(&mut self, bind: Bind, state: KeyState) {
-        let controller_button = match self.config.binds.get(&bind) {
-            Some(ControllerAction::Button(controller_button)) => controller_button,
-            Some(ControllerAction::Analog(_x, _y)) => {
+        let action = match self.config.binds.get(&bind) {
+            Some(action) => *action,
+            None => return,
+        };
+
+        let controller_button = match action {
+            ControllerAction::Analog(x, y) => {
+                match state {
+                    KeyState::Down => {
+                        self.analog_binds.insert(bind, (x, y));
+                    }
+                    KeyState::Up => {
+                        self.analog_binds.remove(&bind);
+                    }
+                }
+
                 return;
             }
-            None => return,
+            ControllerAction::Button(controller_button) => controller_button,
         };
 
-        match *controller_button {
+        match self
+            .config
+            .bind_modes
+            .get(&bind)
+            .copied()
+            .unwrap_or(BindMode::Hold)
+        {
+            BindMode::Hold => self.apply_button_action(bind, controller_button, state),
+
+            BindMode::Toggle => {
+                if state == KeyState::Up {
+                    return;
+                }
+
+                let bind_state = self.bind_states.entry(bind).or_default();
+                bind_state.toggle = !bind_state.toggle;
+                let state = if bind_state.toggle {
+                    KeyState::Down
+                } else {
+                    KeyState::Up
+                };
+
+                self.apply_button_action(bind, controller_button, state);
+            }
+
+            BindMode::TapHold {
+                threshold,
+                tap_action,
+            } => match state {
+                KeyState::Down => {
+                    let bind_state = self.bind_states.entry(bind).or_default();
+                    bind_state.time_pressed = Some(Instant::now());
+                    bind_state.hold_started = false;
+                }
+
+                KeyState::Up => {
+                    let bind_state = self.bind_states.entry(bind).or_default();
+                    let time_pressed = bind_state.time_pressed.take();
+                    let hold_started = bind_state.hold_started;
+                    bind_state.hold_started = false;
+
+                    if hold_started {
+                        self.apply_button_action(bind, controller_button, KeyState::Up);
+                    } else if time_pressed.is_some_and(|t| Self::is_tap(t.elapsed(), threshold)) {
+                        if let ControllerAction::Button(tap_button) = tap_action {
+                            self.apply_button_action(bind, tap_button, KeyState::Down);
+                            self.pending_tap_releases.push((
+                                bind,
+                                tap_button,
+                                Instant::now() + self.config.tap_pulse_duration,
+                            ));
+                        }
+                    }
+                }
+            },
+        }
+    }
+
+    fn apply_button_action(
+        &mut self,
+        bind: Bind,
+        controller_button: ControllerButton,
+        state: KeyState,
+    ) {
+        match controller_button {
             ControllerButton::LeftTrigger => match state {
                 KeyState::Down => self.report.b_left_trigger = u8::MAX,
                 KeyState::Up => self.report.b_left_trigger = 0,
@@ -310,6 +533,59 @@ impl EventHandler {
         }
     }
 
+    fn is_tap(elapsed: Duration, threshold: Duration) -> bool {
+        elapsed < threshold
+    }
+
+    fn update_bind_modifiers(&mut self) {
+        let now = Instant::now();
+
+        let ready_holds: Vec<(Bind, ControllerButton)> = self
+            .config
+            .bind_modes
+            .iter()
+            .filter_map(|(bind, mode)| match mode {
+                BindMode::TapHold { threshold, .. } => Some((*bind, *threshold)),
+                _ => None,
+            })
+            .filter_map(|(bind, threshold)| {
+                let bind_state = self.bind_states.get(&bind)?;
+                if bind_state.hold_started {
+                    return None;
+                }
+
+                let time_pressed = bind_state.time_pressed?;
+                if Self::is_tap(now.duration_since(time_pressed), threshold) {
+                    return None;
+                }
+
+                match self.config.binds.get(&bind)? {
+                    ControllerAction::Button(controller_button) => Some((bind, *controller_button)),
+                    ControllerAction::Analog(..) => None,
+                }
+            })
+            .collect();
+
+        for (bind, controller_button) in ready_holds {
+            self.bind_states.entry(bind).or_default().hold_started = true;
+            self.apply_button_action(bind, controller_button, KeyState::Down);
+        }
+
+        let expired_taps: Vec<(Bind, ControllerButton)> = self
+            .pending_tap_releases
+            .iter()
+            .filter(|(_, _, release_at)| now >= *release_at)
+            .map(|(bind, controller_button, _)| (*bind, *controller_button))
+            .collect();
+
+        self.pending_tap_releases
+            .retain(|(_, _, release_at)| now < *release_at);
+
+        for (bind, controller_button) in expired_taps {
+            self.apply_button_action(bind, controller_button, KeyState::Up);
+        }
+    }
+
     fn handle_jump(&mut self) {
         self.analog_locked = true;
         self.analog_lock_end = Instant::now() + self.config.dodge_lock_duration;
@@ -361,7 +637,72 @@ impl EventHandler {
         self.mouse_samples.push_back((x, y, now));
     }
 
-    fn update_analog(&mut self) {
+    fn handle_rumble(&mut self, large_motor: u8, small_motor: u8) {
+        let intensity = large_motor.max(small_motor) as f64 / u8::MAX as f64;
+
+        let tone_generator = match self.tone_generator.as_mut() {
+            Some(tone_generator) => tone_generator,
+            None => return,
+        };
+
+        tone_generator.set_intensity(intensity);
+
+        if self.config.rumble_alert_enabled {
+            tone_generator.enable(intensity >= self.config.rumble_alert_threshold);
+        }
+    }
+
+    fn capture_bind(&mut self, bind: Bind, action: ControllerAction) {
+        self.config.binds.insert(bind, action);
+
+        if let Err(err) = self.persist_config() {
+            error!("failed to persist rebound config: {:#}", err);
+        }
+    }
+
+    fn persist_config(&self) -> Result<(), anyhow::Error> {
+        // `binds`/`bind_modes` are keyed by the data-carrying `Bind` enum, which
+        // TOML (and JSON) can't represent as a map key. RON has no such
+        // restriction, so it's what the serde impl on `Config` round-trips through.
+        let serialized =
+            ron::ser::to_string_pretty(&self.config, ron::ser::PrettyConfig::default())?;
+        fs::write(&self.config_path, serialized)?;
+
+        Ok(())
+    }
+
+    fn reload_config(&mut self) -> Result<(), anyhow::Error> {
+        let contents = fs::read_to_string(&self.config_path)?;
+        let mut new_config: Config = ron::de::from_str(&contents)?;
+        new_config.response_curve = new_config.response_curve.normalized();
+
+        let oversteer_alert_changed = new_config.oversteer_alert_enabled
+            != self.config.oversteer_alert_enabled
+            || format!("{:?}", new_config.oversteer_alert)
+                != format!("{:?}", self.config.oversteer_alert);
+
+        let tone_generator_needed_changed =
+            Self::tone_generator_needed(&new_config) != Self::tone_generator_needed(&self.config);
+
+        if oversteer_alert_changed || tone_generator_needed_changed {
+            self.tone_generator = match Self::tone_generator_needed(&new_config) {
+                true => Some(ToneGenerator::new(new_config.oversteer_alert)?),
+                false => None,
+            };
+        }
+
+        self.config = new_config;
+
+        Ok(())
+    }
+
+    // The tone generator is shared by two independent alert sources -- oversteer
+    // and rumble feedback -- so its lifetime has to follow whichever of them is on.
+    fn tone_generator_needed(config: &Config) -> bool {
+        config.oversteer_alert_enabled || config.rumble_alert_enabled
+    }
+
+    fn prune_stale_mouse_samples(&mut self) {
         let now = Instant::now();
 
         loop {
@@ -376,31 +717,55 @@ impl EventHandler {
                 break;
             }
         }
+    }
+
+    fn update_analog(&mut self) {
+        // Prune unconditionally, even on the early-return branches below --
+        // otherwise mouse_samples grows unbounded while an analog bind holds
+        // the mouse-sampling and inertia branches off for a long time.
+        self.prune_stale_mouse_samples();
+
+        // Active analog binds take precedence over mouse-driven aiming rather
+        // than being summed with it, so a held bind gives a stable fixed
+        // position instead of one the mouse can still nudge around.
+        if !self.analog_binds.is_empty() {
+            let (x, y) = self
+                .analog_binds
+                .values()
+                .fold((0.0, 0.0), |acc, (x, y)| (acc.0 + x, acc.1 + y));
+
+            self.set_analog_linear(x, y);
+
+            return;
+        }
+
+        if self.config.analog_inertia {
+            self.update_analog_inertia();
+
+            return;
+        }
+
+        let now = Instant::now();
+
+        let window_secs = self.config.sample_window.as_secs_f64();
+        // Guard against a configured tau of zero: `exp(-age / 0.0)` is NaN for a
+        // sample with zero age and the kernel would otherwise poison mouse_vel.
+        let tau_secs = self.config.sample_tau.as_secs_f64().max(f64::EPSILON);
 
-        // let window = self.config.sample_window.as_secs_f64();
         let mut mouse_vel = (0.0, 0.0);
 
-        /*
-        let dt_offset = if self.mouse_samples.len() > 0 {
-            let sample = self.mouse_samples[0];
-            if (now - sample.2).as_secs_f64() * 1000.0 < 1.0 {
-                (now - sample.2).as_secs_f64()
-            } else {
-                0.0005
-            }
-        } else {
-            0.0
-        };
-        */
+        for &(x, y, t) in self.mouse_samples.iter() {
+            let age = (now - t).as_secs_f64();
 
-        for &(x, y, _) in self.mouse_samples.iter() {
-            // let dt = ((now - t).as_secs_f64() - dt_offset) / window;
+            let weight = match self.config.sample_kernel {
+                SampleKernel::Linear => (1.0 - age / window_secs).max(0.0),
+                SampleKernel::Exponential => (-age / tau_secs).exp(),
+            };
 
-            mouse_vel.0 += x as f64;
-            mouse_vel.1 += y as f64;
+            mouse_vel.0 += weight * x as f64;
+            mouse_vel.1 += weight * y as f64;
         }
 
-        // TODO: proper analog binds
         if !self.config.analog_mask.0 {
             mouse_vel.0 = 0.0;
         }
@@ -409,8 +774,12 @@ impl EventHandler {
             mouse_vel.1 = 0.0;
         }
 
-        let multiplier =
-            self.config.sensitivity / (1e4 * self.config.sample_window.as_secs_f64());
+        // `mouse_vel` stays a weighted *sum* over the window (not normalized by
+        // total weight), so it's still a rate, recent-weighted rather than
+        // equally-weighted. Every weight is at most 1, so for steady motion the
+        // output is lower than an unweighted sum at the same sensitivity (about
+        // half with the linear kernel).
+        let multiplier = self.config.sensitivity / (1e4 * window_secs);
 
         self.set_analog_linear(
             mouse_vel.0 as f64 * multiplier,
@@ -418,9 +787,63 @@ impl EventHandler {
         );
     }
 
+    fn update_analog_inertia(&mut self) {
+        let now = Instant::now();
+        let dt_secs = (now - self.analog_velocity_last_update).as_secs_f64();
+        self.analog_velocity_last_update = now;
+
+        self.analog_velocity = Self::decay_velocity(
+            self.analog_velocity,
+            self.config.analog_inertia_friction,
+            dt_secs,
+        );
+
+        let mut impulse = (0.0, 0.0);
+        for &(x, y, _) in self.mouse_samples.iter() {
+            impulse.0 += x as f64;
+            impulse.1 += y as f64;
+        }
+        self.mouse_samples.clear();
+
+        let multiplier = self.config.sensitivity / 1e4;
+
+        self.analog_velocity.0 += impulse.0 * multiplier;
+        self.analog_velocity.1 -= impulse.1 * multiplier;
+
+        const EPSILON: f64 = 1e-3;
+        if self.analog_velocity.0.abs() < EPSILON {
+            self.analog_velocity.0 = 0.0;
+        }
+        if self.analog_velocity.1.abs() < EPSILON {
+            self.analog_velocity.1 = 0.0;
+        }
+
+        if !self.config.analog_mask.0 {
+            self.analog_velocity.0 = 0.0;
+        }
+
+        if !self.config.analog_mask.1 {
+            self.analog_velocity.1 = 0.0;
+        }
+
+        // Inertia drives aim, same as the non-inertia mouse path (`update_analog` ->
+        // `set_analog_linear`) and the analog-bind path, not the WASD-driven left stick.
+        self.set_analog_linear(self.analog_velocity.0, self.analog_velocity.1);
+    }
+
+    fn decay_velocity(velocity: (f64, f64), friction: f64, dt_secs: f64) -> (f64, f64) {
+        let decay = friction.powf(dt_secs);
+        (velocity.0 * decay, velocity.1 * decay)
+    }
+
     fn set_analog(&mut self, x: f64, y: f64) {
-        let alert = x.abs().max(y.abs()) >= self.config.oversteer_alert_threshold;
-        self.tone_generator.as_mut().map(|tg| tg.enable(alert));
+        // Only touch the tone generator's enable state on oversteer's behalf when
+        // oversteer alerting is actually on, so a rumble-only setup (tone_generator
+        // exists but oversteer_alert_enabled is false) isn't clobbered every frame.
+        if self.config.oversteer_alert_enabled {
+            let alert = x.abs().max(y.abs()) >= self.config.oversteer_alert_threshold;
+            self.tone_generator.as_mut().map(|tg| tg.enable(alert));
+        }
 
         if self.config.analog_circularize {
             self.set_analog_circularized(x, y);
@@ -429,7 +852,57 @@ impl EventHandler {
         }
     }
 
+    fn apply_response_curve(&self, x: f64, y: f64) -> (f64, f64) {
+        let radius = (x.powi(2) + y.powi(2)).sqrt();
+
+        if radius == 0.0 {
+            return (0.0, 0.0);
+        }
+
+        let curved_radius = Self::curve_radius(&self.config.response_curve, radius);
+        let scale = curved_radius / radius;
+
+        (x * scale, y * scale)
+    }
+
+    fn curve_radius(curve: &ResponseCurve, radius: f64) -> f64 {
+        match curve {
+            ResponseCurve::None => radius,
+            ResponseCurve::Power { gamma } => radius.powf(*gamma),
+            ResponseCurve::PiecewiseLinear { points } => {
+                Self::interpolate_piecewise(points, radius)
+            }
+        }
+    }
+
+    // Assumes `points` is already sorted ascending by input with no duplicate
+    // inputs -- `ResponseCurve::normalized` establishes that invariant once at
+    // config load time, so this hot-path call doesn't re-sort every frame.
+    fn interpolate_piecewise(points: &[(f64, f64)], input: f64) -> f64 {
+        if points.is_empty() {
+            return input;
+        }
+
+        if input <= points[0].0 {
+            return points[0].1;
+        }
+
+        for window in points.windows(2) {
+            let (x0, y0) = window[0];
+            let (x1, y1) = window[1];
+
+            if input <= x1 {
+                let t = (input - x0) / (x1 - x0);
+                return y0 + t * (y1 - y0);
+            }
+        }
+
+        points[points.len() - 1].1
+    }
+
     fn set_analog_circularized(&mut self, x: f64, y: f64) {
+        let (x, y) = self.apply_response_curve(x, y);
+
         let angle = y.atan2(x);
         let radius = (x.powi(2) + y.powi(2)).sqrt();
 
@@ -438,6 +911,8 @@ impl EventHandler {
     }
 
     fn set_analog_linear(&mut self, x: f64, y: f64) {
+        let (x, y) = self.apply_response_curve(x, y);
+
         if x.abs() <= 1.0 && y.abs() <= 1.0 {
             self.report.s_thumb_rx = (x * Self::ANALOG_MAX) as i16;
             self.report.s_thumb_ry = (y * Self::ANALOG_MAX) as i16;
@@ -456,3 +931,126 @@ impl EventHandler {
         self.report.s_thumb_ry = (angle.sin() * new_radius * Self::ANALOG_MAX) as i16;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decay_velocity_halves_over_one_half_life() {
+        let (vx, vy) = EventHandler::decay_velocity((10.0, -10.0), 0.5, 1.0);
+
+        assert!((vx - 5.0).abs() < 1e-9);
+        assert!((vy - -5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn decay_velocity_is_noop_at_zero_dt() {
+        let (vx, vy) = EventHandler::decay_velocity((3.0, 4.0), 0.1, 0.0);
+
+        assert!((vx - 3.0).abs() < 1e-9);
+        assert!((vy - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn decay_velocity_is_noop_at_full_friction() {
+        let (vx, vy) = EventHandler::decay_velocity((3.0, 4.0), 1.0, 100.0);
+
+        assert!((vx - 3.0).abs() < 1e-9);
+        assert!((vy - 4.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn interpolate_piecewise_interpolates_between_breakpoints() {
+        let points = vec![(0.0, 0.0), (1.0, 2.0)];
+
+        assert!((EventHandler::interpolate_piecewise(&points, 0.5) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn interpolate_piecewise_clamps_outside_the_breakpoint_range() {
+        let points = vec![(0.0, 0.1), (1.0, 0.9)];
+
+        assert!((EventHandler::interpolate_piecewise(&points, -1.0) - 0.1).abs() < 1e-9);
+        assert!((EventHandler::interpolate_piecewise(&points, 2.0) - 0.9).abs() < 1e-9);
+    }
+
+    #[test]
+    fn response_curve_normalized_sorts_unsorted_breakpoints() {
+        let curve = ResponseCurve::PiecewiseLinear {
+            points: vec![(1.0, 2.0), (0.0, 0.0)],
+        };
+
+        match curve.normalized() {
+            ResponseCurve::PiecewiseLinear { points } => {
+                assert_eq!(points, vec![(0.0, 0.0), (1.0, 2.0)]);
+            }
+            other => panic!("expected PiecewiseLinear, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn response_curve_normalized_collapses_duplicate_inputs() {
+        let curve = ResponseCurve::PiecewiseLinear {
+            points: vec![(0.0, 0.0), (1.0, 2.0), (1.0, 5.0)],
+        };
+
+        match curve.normalized() {
+            ResponseCurve::PiecewiseLinear { points } => {
+                assert_eq!(points, vec![(0.0, 0.0), (1.0, 2.0)]);
+            }
+            other => panic!("expected PiecewiseLinear, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn interpolate_piecewise_does_not_divide_by_zero_on_normalized_duplicate_inputs() {
+        let curve = ResponseCurve::PiecewiseLinear {
+            points: vec![(0.0, 0.0), (1.0, 2.0), (1.0, 5.0)],
+        }
+        .normalized();
+
+        let points = match curve {
+            ResponseCurve::PiecewiseLinear { points } => points,
+            other => panic!("expected PiecewiseLinear, got {:?}", other),
+        };
+
+        assert!(EventHandler::interpolate_piecewise(&points, 1.0).is_finite());
+    }
+
+    #[test]
+    fn curve_radius_none_is_identity() {
+        assert_eq!(EventHandler::curve_radius(&ResponseCurve::None, 0.5), 0.5);
+    }
+
+    #[test]
+    fn curve_radius_power_applies_gamma() {
+        let curve = ResponseCurve::Power { gamma: 2.0 };
+
+        assert!((EventHandler::curve_radius(&curve, 0.5) - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn is_tap_true_when_elapsed_under_threshold() {
+        assert!(EventHandler::is_tap(
+            Duration::from_millis(50),
+            Duration::from_millis(200)
+        ));
+    }
+
+    #[test]
+    fn is_tap_false_when_elapsed_reaches_threshold() {
+        assert!(!EventHandler::is_tap(
+            Duration::from_millis(200),
+            Duration::from_millis(200)
+        ));
+    }
+
+    #[test]
+    fn is_tap_false_when_elapsed_exceeds_threshold() {
+        assert!(!EventHandler::is_tap(
+            Duration::from_millis(500),
+            Duration::from_millis(200)
+        ));
+    }
+}